@@ -1,4 +1,7 @@
-use crate::cli::{CaptureArgs, Cli, Cmd, FileType, FinalizeArgs};
+use crate::cli::{
+    CaptureArgs, Cli, Cmd, ConvertArgs, CopyFileArgs, FinalizeArgs, RecordArgs, ResizeFilter,
+};
+use crate::format::OutputFormat;
 use chrono::Local;
 use grim_rs::{Box as GrimBox, CaptureParameters, Grim};
 use image::DynamicImage;
@@ -10,6 +13,10 @@ use std::io::IsTerminal;
 use std::io::Write;
 use std::io::{self, BufRead};
 use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
 pub fn run(cli: Cli) -> i32 {
     match cli.cmd {
@@ -27,6 +34,27 @@ pub fn run(cli: Cli) -> i32 {
                 1
             }
         },
+        Cmd::Convert(args) => match run_convert(args) {
+            Ok(()) => 0,
+            Err(err) => {
+                eprintln!("Error: {err}");
+                1
+            }
+        },
+        Cmd::Record(args) => match run_record(args) {
+            Ok(()) => 0,
+            Err(err) => {
+                eprintln!("Error: {err}");
+                1
+            }
+        },
+        Cmd::CopyFile(args) => match run_copy_file(args) {
+            Ok(()) => 0,
+            Err(err) => {
+                eprintln!("Error: {err}");
+                1
+            }
+        },
     }
 }
 
@@ -41,6 +69,11 @@ impl fmt::Display for FinalizeError {
 
 impl std::error::Error for FinalizeError {}
 
+/// Note: slurp selection only triggers on the explicit `--select`/`-G` flag.
+/// We deliberately did not also wire it up as the implicit default for a bare
+/// `hqs capture` (no `-g`, no `-o`) — that would silently change the
+/// long-standing default of capturing everything, which existing scripts and
+/// muscle memory rely on. Use `--select` (or `-G`) to get the slurp prompt.
 fn run_capture(args: CaptureArgs) -> grim_rs::Result<()> {
     let output_file = if let Some(path) = args.output_file.as_deref() {
         path.to_path_buf()
@@ -54,29 +87,16 @@ fn run_capture(args: CaptureArgs) -> grim_rs::Result<()> {
 
     let mut grim = Grim::new()?;
 
-    let region: Option<GrimBox> = match args.geometry.as_deref() {
-        None => None,
-        Some("-") => Some(Grim::read_region_from_stdin()?),
-        Some(spec) => Some(spec.parse()?),
-    };
-
     // Match grim: if -s isn't provided, default to the greatest output scale factor among
     // outputs intersecting the capture geometry.
-    let scale_region = if let Some(ref output_name) = args.output {
-        let outputs = grim.get_outputs()?;
-        let output = outputs
-            .iter()
-            .find(|o| o.name() == output_name)
-            .ok_or_else(|| grim_rs::Error::OutputNotFound(output_name.clone()))?;
-        Some(*output.geometry())
-    } else {
-        region
-    };
-
-    let default_scale = match args.scale {
-        Some(s) => s,
-        None => grim.greatest_scale_for_region(scale_region)?,
-    };
+    let (region, default_scale) = resolve_region_and_scale(
+        &mut grim,
+        args.geometry.as_deref(),
+        args.select,
+        args.slurp_args.as_deref(),
+        args.output.as_deref(),
+        args.scale,
+    )?;
 
     let result = if let Some(ref output_name) = args.output {
         if let Some(region) = region {
@@ -112,6 +132,86 @@ fn run_capture(args: CaptureArgs) -> grim_rs::Result<()> {
     save_or_write_result(&grim, &result, &output_file, &args)
 }
 
+/// Shared by `capture` and `record`: resolves `-g`/`--select`/`--slurp` into an
+/// optional region, then picks a capture scale — either the explicit one
+/// given, or (matching grim) the greatest scale factor among outputs
+/// intersecting the region/output being captured.
+fn resolve_region_and_scale(
+    grim: &mut Grim,
+    geometry: Option<&str>,
+    select: bool,
+    slurp_args: Option<&str>,
+    output: Option<&str>,
+    explicit_scale: Option<f64>,
+) -> grim_rs::Result<(Option<GrimBox>, f64)> {
+    let region: Option<GrimBox> = if select {
+        if geometry.is_some() {
+            return Err(grim_rs::Error::Io(io::Error::other(
+                "-g/--select are mutually exclusive",
+            )));
+        }
+        Some(select_region_with_slurp(slurp_args)?)
+    } else {
+        match geometry {
+            None => None,
+            Some("-") => Some(Grim::read_region_from_stdin()?),
+            Some(spec) => Some(spec.parse()?),
+        }
+    };
+
+    let scale_region = if let Some(output_name) = output {
+        let outputs = grim.get_outputs()?;
+        let output = outputs
+            .iter()
+            .find(|o| o.name() == output_name)
+            .ok_or_else(|| grim_rs::Error::OutputNotFound(output_name.to_string()))?;
+        Some(*output.geometry())
+    } else {
+        region
+    };
+
+    let scale = match explicit_scale {
+        Some(s) => s,
+        None => grim.greatest_scale_for_region(scale_region)?,
+    };
+
+    Ok((region, scale))
+}
+
+/// Spawns `slurp` to let the user drag out a region interactively, then parses its
+/// `x,y wxh` stdout into a `GrimBox` (the same format `-g` already accepts).
+fn select_region_with_slurp(extra_args: Option<&str>) -> grim_rs::Result<GrimBox> {
+    use std::process::Command;
+
+    let mut cmd = Command::new("slurp");
+    if let Some(extra) = extra_args {
+        cmd.args(extra.split_whitespace());
+    }
+
+    let output = cmd.output().map_err(|e| {
+        grim_rs::Error::Io(io::Error::new(
+            e.kind(),
+            format!("Failed to run 'slurp' (is it installed?): {e}"),
+        ))
+    })?;
+
+    if !output.status.success() {
+        return Err(grim_rs::Error::Io(io::Error::other(
+            "Selection cancelled",
+        )));
+    }
+
+    let spec = String::from_utf8_lossy(&output.stdout);
+    let spec = spec.trim();
+    if spec.is_empty() {
+        return Err(grim_rs::Error::Io(io::Error::other(
+            "Selection cancelled",
+        )));
+    }
+
+    spec.parse()
+}
+
 fn run_finalize(args: FinalizeArgs) -> Result<(), FinalizeError> {
     let [x, y, w, h] = parse_crop_px(&args.crop_px)?;
 
@@ -123,6 +223,8 @@ fn run_finalize(args: FinalizeArgs) -> Result<(), FinalizeError> {
     })?;
 
     let cropped = crop_image_px(&base, x, y, w, h)?;
+    let resized = apply_resize(cropped, &args)?;
+    let decorated = apply_decorations(resized, &args)?;
 
     let output_path = if let Some(path) = args.output_file.as_deref() {
         path.to_path_buf()
@@ -131,7 +233,7 @@ fn run_finalize(args: FinalizeArgs) -> Result<(), FinalizeError> {
     };
 
     if output_path == Path::new("-") {
-        write_png_to_stdout_image(&cropped)?;
+        write_png_to_stdout_image(&decorated, args.optimize)?;
         return Ok(());
     }
 
@@ -146,7 +248,7 @@ fn run_finalize(args: FinalizeArgs) -> Result<(), FinalizeError> {
         })?;
     }
 
-    save_dynamic_image(&cropped, &output_path).map_err(|e| {
+    save_dynamic_image(&decorated, &output_path, 80, args.optimize).map_err(|e| {
         FinalizeError(format!(
             "Failed to save output '{}': {e}",
             output_path.display()
@@ -156,6 +258,240 @@ fn run_finalize(args: FinalizeArgs) -> Result<(), FinalizeError> {
     Ok(())
 }
 
+fn run_convert(args: ConvertArgs) -> Result<(), FinalizeError> {
+    if args.list_formats {
+        print_supported_formats();
+        return Ok(());
+    }
+
+    let input = args.input.ok_or_else(|| {
+        FinalizeError("convert requires an input path (or --list-formats)".to_string())
+    })?;
+    let output = args
+        .output
+        .ok_or_else(|| FinalizeError("convert requires an output path".to_string()))?;
+
+    let image = image::open(&input).map_err(|e| {
+        FinalizeError(format!("Failed to open input '{}': {e}", input.display()))
+    })?;
+
+    save_dynamic_image(&image, &output, args.quality, args.optimize).map_err(|e| {
+        FinalizeError(format!("Failed to save output '{}': {e}", output.display()))
+    })?;
+
+    Ok(())
+}
+
+fn print_supported_formats() {
+    println!("Supported output formats:");
+    for format in OutputFormat::ALL {
+        println!(
+            "  {format:<6} {:<24} .{}",
+            format.mime_type(),
+            format.extensions().join(", .")
+        );
+    }
+    println!(
+        "\nRecognized extensions: {}",
+        crate::format::supported_extensions().join(", ")
+    );
+}
+
+/// Spawns `wl-copy -t <mime>` with the given file as stdin, putting its
+/// contents on the Wayland clipboard.
+fn run_copy_file(args: CopyFileArgs) -> grim_rs::Result<()> {
+    let file = fs::File::open(&args.path).map_err(|e| {
+        grim_rs::Error::Io(io::Error::new(
+            e.kind(),
+            format!("Failed to open '{}': {e}", args.path.display()),
+        ))
+    })?;
+
+    let status = Command::new("wl-copy")
+        .args(["-t", &args.mime_type])
+        .stdin(Stdio::from(file))
+        .status()
+        .map_err(|e| {
+            grim_rs::Error::Io(io::Error::new(
+                e.kind(),
+                format!("Failed to run 'wl-copy' (is it installed?): {e}"),
+            ))
+        })?;
+
+    if !status.success() {
+        return Err(grim_rs::Error::Io(io::Error::other(
+            "wl-copy exited with an error",
+        )));
+    }
+
+    Ok(())
+}
+
+/// Captures frames at `args.fps` and pipes raw RGBA into an `ffmpeg` child
+/// process over stdin, which encodes them straight to `args.output_file`
+/// (format chosen by extension). Ctrl-C stops the capture loop and closes
+/// ffmpeg's stdin so it finalizes the container instead of leaving it broken.
+fn run_record(args: RecordArgs) -> grim_rs::Result<()> {
+    let mut grim = Grim::new()?;
+
+    let (region, scale) = resolve_region_and_scale(
+        &mut grim,
+        args.geometry.as_deref(),
+        args.select,
+        args.slurp_args.as_deref(),
+        args.output.as_deref(),
+        None,
+    )?;
+
+    let ext = args
+        .output_file
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let codec_args = ffmpeg_codec_args(&ext)?;
+
+    let first_frame = capture_record_frame(&mut grim, &args, region, scale)?;
+    let (width, height) = (first_frame.width(), first_frame.height());
+
+    let mut ffmpeg = Command::new("ffmpeg")
+        .args([
+            "-y",
+            "-f",
+            "rawvideo",
+            "-pix_fmt",
+            "rgba",
+            "-s",
+            &format!("{width}x{height}"),
+            "-r",
+            &args.fps.to_string(),
+            "-i",
+            "-",
+        ])
+        .args(codec_args)
+        .arg(&args.output_file)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .spawn()
+        .map_err(|e| {
+            grim_rs::Error::Io(io::Error::new(
+                e.kind(),
+                format!("Failed to run 'ffmpeg' (is it installed?): {e}"),
+            ))
+        })?;
+
+    let mut stdin = ffmpeg
+        .stdin
+        .take()
+        .expect("ffmpeg was spawned with a piped stdin");
+
+    let running = Arc::new(AtomicBool::new(true));
+    {
+        let running = Arc::clone(&running);
+        ctrlc::set_handler(move || running.store(false, Ordering::SeqCst)).map_err(|e| {
+            grim_rs::Error::Io(io::Error::other(format!(
+                "Failed to install Ctrl-C handler: {e}"
+            )))
+        })?;
+    }
+
+    let frame_interval = Duration::from_secs_f64(1.0 / args.fps as f64);
+    let start = Instant::now();
+    let mut next_frame = start + frame_interval;
+
+    let _ = stdin.write_all(first_frame.data());
+
+    while running.load(Ordering::SeqCst) {
+        if let Some(duration) = args.duration
+            && start.elapsed().as_secs_f64() >= duration
+        {
+            break;
+        }
+
+        let now = Instant::now();
+        if now < next_frame {
+            std::thread::sleep(next_frame - now);
+        }
+        next_frame += frame_interval;
+
+        let frame = capture_record_frame(&mut grim, &args, region, scale)?;
+        if stdin.write_all(frame.data()).is_err() {
+            break;
+        }
+    }
+
+    drop(stdin);
+    let status = ffmpeg.wait().map_err(grim_rs::Error::Io)?;
+    if !status.success() {
+        return Err(grim_rs::Error::Io(io::Error::other(
+            "ffmpeg exited with an error",
+        )));
+    }
+
+    Ok(())
+}
+
+fn capture_record_frame(
+    grim: &mut Grim,
+    args: &RecordArgs,
+    region: Option<GrimBox>,
+    scale: f64,
+) -> grim_rs::Result<grim_rs::CaptureResult> {
+    if let Some(ref output_name) = args.output {
+        if let Some(region) = region {
+            let params = CaptureParameters::new(output_name.clone())
+                .overlay_cursor(args.cursor)
+                .region(region);
+            let multi_result = grim.capture_outputs_with_scale(vec![params], scale)?;
+            multi_result
+                .get(output_name)
+                .cloned()
+                .ok_or_else(|| grim_rs::Error::OutputNotFound(output_name.clone()))
+        } else if args.cursor {
+            grim.capture_output_with_scale_and_cursor(output_name, scale, true)
+        } else {
+            grim.capture_output_with_scale(output_name, scale)
+        }
+    } else if let Some(region) = region {
+        if args.cursor {
+            grim.capture_region_with_scale_and_cursor(region, scale, true)
+        } else {
+            grim.capture_region_with_scale(region, scale)
+        }
+    } else if args.cursor {
+        grim.capture_all_with_scale_and_cursor(scale, true)
+    } else {
+        grim.capture_all_with_scale(scale)
+    }
+}
+
+/// Maps an output extension to the ffmpeg video codec args that produce it.
+fn ffmpeg_codec_args(ext: &str) -> grim_rs::Result<Vec<&'static str>> {
+    match ext {
+        "mp4" => Ok(vec![
+            "-c:v",
+            "libx264",
+            "-pix_fmt",
+            "yuv420p",
+            "-movflags",
+            "+faststart",
+        ]),
+        "webm" => Ok(vec![
+            "-c:v",
+            "libvpx-vp9",
+            "-pix_fmt",
+            "yuv420p",
+            "-b:v",
+            "0",
+            "-crf",
+            "30",
+        ]),
+        other => Err(grim_rs::Error::Io(io::Error::other(format!(
+            "Unsupported recording extension '.{other}', expected .mp4 or .webm"
+        )))),
+    }
+}
+
 fn parse_crop_px(values: &[u32]) -> Result<[u32; 4], FinalizeError> {
     if values.len() != 4 {
         return Err(FinalizeError(
@@ -193,6 +529,259 @@ fn crop_image_px(
     Ok(image.crop_imm(x, y, w, h))
 }
 
+/// Applies `--resize`/`--resize-pct` to `image` after cropping. `--resize WxH`
+/// fits the image within the box while preserving aspect ratio (like
+/// ImageMagick's geometry); a trailing `>` makes it a no-op when the image is
+/// already within the box.
+fn apply_resize(image: DynamicImage, args: &FinalizeArgs) -> Result<DynamicImage, FinalizeError> {
+    if args.resize.is_some() && args.resize_pct.is_some() {
+        return Err(FinalizeError(
+            "--resize and --resize-pct are mutually exclusive".to_string(),
+        ));
+    }
+
+    if let Some(pct) = args.resize_pct {
+        if pct <= 0.0 {
+            return Err(FinalizeError("--resize-pct must be > 0".to_string()));
+        }
+        let (w, h) = image.dimensions();
+        let target_w = ((w as f64 * pct / 100.0).round() as u32).max(1);
+        let target_h = ((h as f64 * pct / 100.0).round() as u32).max(1);
+        let filter = resize_filter(args.filter, target_w <= w && target_h <= h);
+        return Ok(image.resize_exact(target_w, target_h, filter));
+    }
+
+    if let Some(spec) = args.resize.as_deref() {
+        let (box_w, box_h, shrink_only) = parse_resize_spec(spec)?;
+        let (w, h) = image.dimensions();
+        if shrink_only && w <= box_w && h <= box_h {
+            return Ok(image);
+        }
+        let filter = resize_filter(args.filter, box_w < w || box_h < h);
+        return Ok(image.resize(box_w, box_h, filter));
+    }
+
+    Ok(image)
+}
+
+fn parse_resize_spec(spec: &str) -> Result<(u32, u32, bool), FinalizeError> {
+    let invalid = || FinalizeError(format!("Invalid --resize value '{spec}', expected WxH or WxH>"));
+
+    let (dims, shrink_only) = match spec.strip_suffix('>') {
+        Some(stripped) => (stripped, true),
+        None => (spec, false),
+    };
+
+    let (w, h) = dims.split_once('x').ok_or_else(invalid)?;
+    let w: u32 = w.parse().map_err(|_| invalid())?;
+    let h: u32 = h.parse().map_err(|_| invalid())?;
+    if w == 0 || h == 0 {
+        return Err(FinalizeError("--resize width/height must be > 0".to_string()));
+    }
+
+    Ok((w, h, shrink_only))
+}
+
+fn resize_filter(requested: Option<ResizeFilter>, is_downscale: bool) -> image::imageops::FilterType {
+    match requested {
+        Some(f) => f.into(),
+        None if is_downscale => image::imageops::FilterType::Lanczos3,
+        None => image::imageops::FilterType::Nearest,
+    }
+}
+
+impl From<ResizeFilter> for image::imageops::FilterType {
+    fn from(filter: ResizeFilter) -> Self {
+        match filter {
+            ResizeFilter::Nearest => image::imageops::FilterType::Nearest,
+            ResizeFilter::Triangle => image::imageops::FilterType::Triangle,
+            ResizeFilter::CatmullRom => image::imageops::FilterType::CatmullRom,
+            ResizeFilter::Gaussian => image::imageops::FilterType::Gaussian,
+            ResizeFilter::Lanczos3 => image::imageops::FilterType::Lanczos3,
+        }
+    }
+}
+
+/// Applies `--pad`/`--bg`/`--shadow`/`--radius` after cropping and resizing:
+/// round the image's corners, then composite it onto a padded canvas with an
+/// optional blurred drop shadow behind it.
+fn apply_decorations(image: DynamicImage, args: &FinalizeArgs) -> Result<DynamicImage, FinalizeError> {
+    if args.pad == 0 && !args.shadow && args.radius == 0 && args.bg.is_none() {
+        return Ok(image);
+    }
+
+    let bg = parse_bg_color(args.bg.as_deref())?;
+    let mut rgba = image.to_rgba8();
+    let radius = args.radius.min(rgba.width().min(rgba.height()) / 2);
+    if radius > 0 {
+        round_corners(&mut rgba, radius);
+    }
+
+    // A shadow is drawn behind the image and blurred outward, so with no
+    // padding the image itself covers the canvas edge-to-edge and the shadow
+    // never peeks out. Fall back to enough padding for the blur to be visible
+    // rather than silently doing nothing.
+    const MIN_SHADOW_PAD: u32 = 16;
+    let pad = if args.shadow && args.pad == 0 {
+        eprintln!(
+            "Warning: --shadow has no visible effect with --pad 0; using --pad {MIN_SHADOW_PAD}"
+        );
+        MIN_SHADOW_PAD
+    } else {
+        args.pad
+    };
+
+    let canvas_w = rgba.width() + 2 * pad;
+    let canvas_h = rgba.height() + 2 * pad;
+    let mut canvas = image::RgbaImage::from_pixel(canvas_w, canvas_h, bg);
+
+    if args.shadow {
+        let shadow = render_shadow(rgba.width(), rgba.height(), radius, canvas_w, canvas_h, pad);
+        image::imageops::overlay(&mut canvas, &shadow, 0, 0);
+    }
+
+    image::imageops::overlay(&mut canvas, &rgba, pad as i64, pad as i64);
+
+    Ok(DynamicImage::ImageRgba8(canvas))
+}
+
+fn parse_bg_color(spec: Option<&str>) -> Result<image::Rgba<u8>, FinalizeError> {
+    let Some(spec) = spec else {
+        return Ok(image::Rgba([0, 0, 0, 0]));
+    };
+
+    let invalid = || FinalizeError(format!("Invalid --bg value '{spec}', expected RRGGBB or RRGGBBAA"));
+    let hex = spec.strip_prefix('#').unwrap_or(spec);
+
+    let channels = match hex.len() {
+        6 => {
+            let rgb = u32::from_str_radix(hex, 16).map_err(|_| invalid())?;
+            [(rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8, 255]
+        }
+        8 => {
+            let rgba = u32::from_str_radix(hex, 16).map_err(|_| invalid())?;
+            [
+                (rgba >> 24) as u8,
+                (rgba >> 16) as u8,
+                (rgba >> 8) as u8,
+                rgba as u8,
+            ]
+        }
+        _ => return Err(invalid()),
+    };
+
+    Ok(image::Rgba(channels))
+}
+
+/// Zeroes alpha outside a quarter-circle of radius `radius` in each of the
+/// image's four corners.
+fn round_corners(image: &mut image::RgbaImage, radius: u32) {
+    if radius == 0 {
+        return;
+    }
+
+    let (w, h) = image.dimensions();
+    let r = radius as i64;
+    let corners = [
+        (0i64, 0i64, r, r),
+        (w as i64 - r, 0, w as i64 - r - 1, r),
+        (0, h as i64 - r, r, h as i64 - r - 1),
+        (w as i64 - r, h as i64 - r, w as i64 - r - 1, h as i64 - r - 1),
+    ];
+
+    for (x0, y0, cx, cy) in corners {
+        for dy in 0..r {
+            for dx in 0..r {
+                let x = x0 + dx;
+                let y = y0 + dy;
+                if x < 0 || y < 0 || x >= w as i64 || y >= h as i64 {
+                    continue;
+                }
+                let ddx = (x - cx) as f64;
+                let ddy = (y - cy) as f64;
+                if (ddx * ddx + ddy * ddy).sqrt() > r as f64 {
+                    image.get_pixel_mut(x as u32, y as u32).0[3] = 0;
+                }
+            }
+        }
+    }
+}
+
+/// Renders a soft drop shadow the size of `canvas_w` x `canvas_h`: a dark
+/// rounded rectangle, offset a few pixels down-right of where the image will
+/// sit, blurred with a separable Gaussian.
+fn render_shadow(img_w: u32, img_h: u32, radius: u32, canvas_w: u32, canvas_h: u32, pad: u32) -> image::RgbaImage {
+    const OFFSET: i64 = 6;
+    const SIGMA: f64 = 4.0;
+
+    let mut rect = image::RgbaImage::from_pixel(img_w, img_h, image::Rgba([0, 0, 0, 170]));
+    if radius > 0 {
+        round_corners(&mut rect, radius);
+    }
+
+    let mut layer = image::RgbaImage::from_pixel(canvas_w, canvas_h, image::Rgba([0, 0, 0, 0]));
+    image::imageops::overlay(&mut layer, &rect, pad as i64 + OFFSET, pad as i64 + OFFSET);
+
+    gaussian_blur_rgba(&layer, SIGMA)
+}
+
+/// Separable Gaussian blur (horizontal pass, then vertical) with kernel radius
+/// ~= 3*sigma. The shadow layer is a uniform color (black) with only alpha
+/// varying, so blurring each channel independently doesn't introduce fringing.
+fn gaussian_blur_rgba(image: &image::RgbaImage, sigma: f64) -> image::RgbaImage {
+    let radius = (3.0 * sigma).ceil().max(1.0) as i32;
+    let kernel = gaussian_kernel(radius, sigma);
+    let (w, h) = image.dimensions();
+
+    let mut horiz = image::RgbaImage::new(w, h);
+    for y in 0..h {
+        for x in 0..w {
+            let mut acc = [0f64; 4];
+            for (k, &weight) in kernel.iter().enumerate() {
+                let dx = k as i32 - radius;
+                let sx = (x as i32 + dx).clamp(0, w as i32 - 1) as u32;
+                let p = image.get_pixel(sx, y);
+                for c in 0..4 {
+                    acc[c] += p.0[c] as f64 * weight;
+                }
+            }
+            horiz.put_pixel(x, y, image::Rgba(acc.map(|v| v.round() as u8)));
+        }
+    }
+
+    let mut out = image::RgbaImage::new(w, h);
+    for y in 0..h {
+        for x in 0..w {
+            let mut acc = [0f64; 4];
+            for (k, &weight) in kernel.iter().enumerate() {
+                let dy = k as i32 - radius;
+                let sy = (y as i32 + dy).clamp(0, h as i32 - 1) as u32;
+                let p = horiz.get_pixel(x, sy);
+                for c in 0..4 {
+                    acc[c] += p.0[c] as f64 * weight;
+                }
+            }
+            out.put_pixel(x, y, image::Rgba(acc.map(|v| v.round() as u8)));
+        }
+    }
+
+    out
+}
+
+fn gaussian_kernel(radius: i32, sigma: f64) -> Vec<f64> {
+    let mut kernel: Vec<f64> = (-radius..=radius)
+        .map(|i| {
+            let x = i as f64;
+            (-x * x / (2.0 * sigma * sigma)).exp()
+        })
+        .collect();
+    let sum: f64 = kernel.iter().sum();
+    for v in &mut kernel {
+        *v /= sum;
+    }
+    kernel
+}
+
 fn generate_finalize_filename(ext: &str) -> String {
     let now = Local::now();
     let timestamp = now.format("%Y%m%d_%Hh%Mm%Ss");
@@ -204,13 +793,14 @@ fn generate_default_finalize_filename() -> PathBuf {
     PathBuf::from(generate_finalize_filename("png"))
 }
 
-fn write_png_to_stdout_image(image: &DynamicImage) -> Result<(), FinalizeError> {
+fn write_png_to_stdout_image(image: &DynamicImage, optimize: u8) -> Result<(), FinalizeError> {
     use std::io::Cursor;
 
     let mut bytes = Vec::new();
     image
         .write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)
         .map_err(|e| FinalizeError(format!("Failed to encode PNG: {e}")))?;
+    let bytes = crate::optimize::optimize_png(&bytes, optimize);
 
     let mut stdout = io::stdout().lock();
     stdout
@@ -219,31 +809,44 @@ fn write_png_to_stdout_image(image: &DynamicImage) -> Result<(), FinalizeError>
     Ok(())
 }
 
-fn save_dynamic_image(image: &DynamicImage, path: &Path) -> Result<(), image::ImageError> {
-    match path
+fn save_dynamic_image(
+    image: &DynamicImage,
+    path: &Path,
+    jpeg_quality: u8,
+    optimize: u8,
+) -> Result<(), image::ImageError> {
+    let format = path
         .extension()
         .and_then(|e| e.to_str())
-        .map(|s| s.to_ascii_lowercase())
-    {
-        Some(ext) if ext == "jpg" || ext == "jpeg" => {
+        .and_then(OutputFormat::from_extension)
+        .unwrap_or(OutputFormat::Png);
+
+    match format {
+        format if format.is_lossy() => {
             use image::ColorType;
             use image::codecs::jpeg::JpegEncoder;
             use std::io::BufWriter;
 
             let file = fs::File::create(path)?;
             let mut writer = BufWriter::new(file);
-            let mut encoder = JpegEncoder::new_with_quality(&mut writer, 80);
+            let mut encoder = JpegEncoder::new_with_quality(&mut writer, jpeg_quality);
             let rgb = image.to_rgb8();
             encoder.encode(&rgb, rgb.width(), rgb.height(), ColorType::Rgb8.into())
         }
-        Some(ext) if ext == "png" => image.save_with_format(path, image::ImageFormat::Png),
-        Some(ext) if ext == "ppm" || ext == "pnm" => {
-            image.save_with_format(path, image::ImageFormat::Pnm)
-        }
-        _ => image.save_with_format(path, image::ImageFormat::Png),
+        OutputFormat::Png => save_optimized_png(image, path, optimize),
+        other => image.save_with_format(path, other.image_format()),
     }
 }
 
+fn save_optimized_png(image: &DynamicImage, path: &Path, optimize: u8) -> Result<(), image::ImageError> {
+    use std::io::Cursor;
+
+    let mut bytes = Vec::new();
+    image.write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)?;
+    let bytes = crate::optimize::optimize_png(&bytes, optimize);
+    fs::write(path, bytes).map_err(image::ImageError::IoError)
+}
+
 fn save_or_write_result(
     grim: &Grim,
     result: &grim_rs::CaptureResult,
@@ -263,9 +866,10 @@ fn write_to_stdout(
     args: &CaptureArgs,
 ) -> grim_rs::Result<()> {
     match args.filetype {
-        FileType::Png => write_png_to_stdout(grim, result, args.level),
-        FileType::Ppm => grim.write_ppm_to_stdout(result.data(), result.width(), result.height()),
-        FileType::Jpeg => write_jpeg_to_stdout(grim, result, args.quality),
+        OutputFormat::Png => write_png_to_stdout(grim, result, args.level, args.optimize),
+        OutputFormat::Ppm => grim.write_ppm_to_stdout(result.data(), result.width(), result.height()),
+        OutputFormat::Jpeg => write_jpeg_to_stdout(grim, result, args.quality),
+        other => write_generic_to_stdout(result, other),
     }
 }
 
@@ -276,9 +880,10 @@ fn save_to_file(
     args: &CaptureArgs,
 ) -> grim_rs::Result<()> {
     match args.filetype {
-        FileType::Png => save_png_to_file(grim, result, output_file, args.level),
-        FileType::Ppm => grim.save_ppm(result.data(), result.width(), result.height(), output_file),
-        FileType::Jpeg => save_jpeg_to_file(grim, result, output_file, args.quality),
+        OutputFormat::Png => save_png_to_file(grim, result, output_file, args.level, args.optimize),
+        OutputFormat::Ppm => grim.save_ppm(result.data(), result.width(), result.height(), output_file),
+        OutputFormat::Jpeg => save_jpeg_to_file(grim, result, output_file, args.quality),
+        other => save_generic_to_file(result, output_file, other),
     }
 }
 
@@ -286,7 +891,12 @@ fn write_png_to_stdout(
     grim: &Grim,
     result: &grim_rs::CaptureResult,
     compression_level: u8,
+    optimize: u8,
 ) -> grim_rs::Result<()> {
+    if optimize > 0 {
+        return write_optimized_png_to_stdout(result, optimize);
+    }
+
     if compression_level == 6 {
         grim.write_png_to_stdout(result.data(), result.width(), result.height())
     } else {
@@ -304,7 +914,12 @@ fn save_png_to_file(
     result: &grim_rs::CaptureResult,
     path: &Path,
     compression_level: u8,
+    optimize: u8,
 ) -> grim_rs::Result<()> {
+    if optimize > 0 {
+        return save_optimized_png_to_file(result, path, optimize);
+    }
+
     if compression_level == 6 {
         grim.save_png(result.data(), result.width(), result.height(), path)
     } else {
@@ -354,16 +969,85 @@ fn save_jpeg_to_file(
     }
 }
 
-fn generate_default_filename(filetype: FileType) -> grim_rs::Result<PathBuf> {
+/// grim has no native QOI/WebP encoders, so these formats go through the `image`
+/// crate instead: rebuild a `DynamicImage` from the raw RGBA buffer grim gives us.
+fn dynamic_image_from_result(result: &grim_rs::CaptureResult) -> DynamicImage {
+    let buf = image::RgbaImage::from_raw(result.width(), result.height(), result.data().to_vec())
+        .expect("capture buffer size matches width * height * 4");
+    DynamicImage::ImageRgba8(buf)
+}
+
+fn write_generic_to_stdout(
+    result: &grim_rs::CaptureResult,
+    format: OutputFormat,
+) -> grim_rs::Result<()> {
+    use std::io::Cursor;
+
+    let image = dynamic_image_from_result(result);
+
+    let mut bytes = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut bytes), format.image_format())
+        .map_err(|e| grim_rs::Error::Io(io::Error::other(format!("Failed to encode {format}: {e}"))))?;
+
+    io::stdout().write_all(&bytes).map_err(grim_rs::Error::Io)?;
+    Ok(())
+}
+
+/// Like `write_generic_to_stdout`, but for PNG with `--optimize` set: grim's own
+/// PNG writer streams straight to stdout, so optimizing means re-encoding
+/// through the `image` crate first to get bytes we can run through the
+/// optimizer before they go out.
+fn write_optimized_png_to_stdout(result: &grim_rs::CaptureResult, optimize: u8) -> grim_rs::Result<()> {
+    use std::io::Cursor;
+
+    let image = dynamic_image_from_result(result);
+    let mut bytes = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)
+        .map_err(|e| grim_rs::Error::Io(io::Error::other(format!("Failed to encode PNG: {e}"))))?;
+    let bytes = crate::optimize::optimize_png(&bytes, optimize);
+
+    io::stdout().write_all(&bytes).map_err(grim_rs::Error::Io)?;
+    Ok(())
+}
+
+fn save_optimized_png_to_file(
+    result: &grim_rs::CaptureResult,
+    path: &Path,
+    optimize: u8,
+) -> grim_rs::Result<()> {
+    use std::io::Cursor;
+
+    let image = dynamic_image_from_result(result);
+    let mut bytes = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)
+        .map_err(|e| grim_rs::Error::Io(io::Error::other(format!("Failed to encode PNG: {e}"))))?;
+    let bytes = crate::optimize::optimize_png(&bytes, optimize);
+
+    fs::write(path, bytes).map_err(grim_rs::Error::Io)
+}
+
+fn save_generic_to_file(
+    result: &grim_rs::CaptureResult,
+    path: &Path,
+    format: OutputFormat,
+) -> grim_rs::Result<()> {
+    let image = dynamic_image_from_result(result);
+    image.save_with_format(path, format.image_format()).map_err(|e| {
+        grim_rs::Error::Io(io::Error::other(format!(
+            "Failed to save {format} to '{}': {e}",
+            path.display()
+        )))
+    })
+}
+
+fn generate_default_filename(filetype: OutputFormat) -> grim_rs::Result<PathBuf> {
     // Format: YYYYMMDD_HHhMMmSSs_hqs.ext (e.g., 20241004_10h30m45s_hqs.png)
     let now = Local::now();
     let timestamp = now.format("%Y%m%d_%Hh%Mm%Ss");
-
-    let ext = match filetype {
-        FileType::Png => "png",
-        FileType::Ppm => "ppm",
-        FileType::Jpeg => "jpeg",
-    };
+    let ext = filetype.extensions()[0];
 
     let output_dir = get_output_dir();
     let filename = format!("{}_hqs.{}", timestamp, ext);
@@ -1,5 +1,5 @@
+use crate::format::OutputFormat;
 use clap::{Args, Parser, Subcommand, ValueEnum};
-use std::fmt;
 use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
@@ -20,7 +20,7 @@ pub enum Cmd {
 
     #[command(
         about = "Finalize an existing image (crop, output)",
-        after_help = "If output-file is '-', output to standard output.\nIf no output-file is specified, use a default timestamped filename.\n\nExamples:\n  hqs finalize --base shot.png --crop-px 0 0 200 200 out.png\n  hqs finalize --base shot.png --crop-px 10 10 800 600 - | wl-copy -t image/png"
+        after_help = "If output-file is '-', output to standard output.\nIf no output-file is specified, use a default timestamped filename.\n\nExamples:\n  hqs finalize --base shot.png --crop-px 0 0 200 200 out.png\n  hqs finalize --base shot.png --crop-px 10 10 800 600 - | wl-copy -t image/png\n  hqs finalize --base shot.png --crop-px 0 0 1920 1080 --resize 800x600 out.png"
     )]
     Finalize(FinalizeArgs),
 
@@ -29,25 +29,28 @@ pub enum Cmd {
         after_help = "Example:\n  hqs copy-file --type image/png ./image.png"
     )]
     CopyFile(CopyFileArgs),
-}
 
-#[derive(ValueEnum, Debug, Clone, Copy)]
-pub enum FileType {
-    Png,
-    Ppm,
-    #[value(alias = "jpg")]
-    Jpeg,
+    #[command(
+        about = "Convert an image between supported formats",
+        after_help = "Examples:\n  hqs convert in.png out.jpg -q 90\n  hqs convert --list-formats"
+    )]
+    Convert(ConvertArgs),
+
+    #[command(
+        about = "Record the screen to a video file via ffmpeg",
+        after_help = "Output format (H.264/VP9) is chosen by the output file's extension (.mp4/.webm).\nPress Ctrl-C to stop recording; ffmpeg finalizes the container cleanly.\n\nExamples:\n  hqs record out.mp4\n  hqs record --select --duration 10 clip.webm"
+    )]
+    Record(RecordArgs),
 }
 
-impl fmt::Display for FileType {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let s = match self {
-            FileType::Png => "png",
-            FileType::Ppm => "ppm",
-            FileType::Jpeg => "jpeg",
-        };
-        f.write_str(s)
-    }
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum ResizeFilter {
+    Nearest,
+    Triangle,
+    #[value(name = "catmull-rom")]
+    CatmullRom,
+    Gaussian,
+    Lanczos3,
 }
 
 #[derive(Args, Debug)]
@@ -66,14 +69,28 @@ pub struct CaptureArgs {
     )]
     pub geometry: Option<String>,
 
+    #[arg(
+        short = 'G',
+        long = "select",
+        help = "Interactively select the region to capture using slurp. Plain `capture` with no -g/-o still captures everything; -G is the only trigger for slurp."
+    )]
+    pub select: bool,
+
+    #[arg(
+        long = "slurp",
+        value_name = "args",
+        help = "Extra arguments to forward to slurp, e.g. \"-d -c ff0000\"."
+    )]
+    pub slurp_args: Option<String>,
+
     #[arg(
         short = 't',
-        value_name = "png|ppm|jpeg|jpg",
-        default_value_t = FileType::Png,
+        value_name = "png|ppm|jpeg|jpg|qoi|webp",
+        default_value_t = OutputFormat::Png,
         hide_possible_values = true,
-        help = "Set the output filetype."
+        help = "Set the output filetype. Note: webp is lossless-only here (no quality knob), unlike jpeg."
     )]
-    pub filetype: FileType,
+    pub filetype: OutputFormat,
 
     #[arg(
         short = 'q',
@@ -93,6 +110,16 @@ pub struct CaptureArgs {
     )]
     pub level: u8,
 
+    #[arg(
+        short = 'O',
+        long = "optimize",
+        value_name = "level",
+        value_parser = clap::value_parser!(u8).range(0..=6),
+        default_value_t = 0,
+        help = "Lossless PNG optimization level (0 = off, 6 = most exhaustive)."
+    )]
+    pub optimize: u8,
+
     #[arg(
         short = 'o',
         value_name = "output",
@@ -124,10 +151,122 @@ pub struct FinalizeArgs {
     #[arg(long, help = "Delete the base file after a successful finalize.")]
     pub delete_base: bool,
 
+    #[arg(
+        long = "resize",
+        value_name = "WxH[>]",
+        help = "Fit the cropped image within WxH, preserving aspect ratio. A trailing '>' only shrinks, never enlarges (e.g. 800x600>)."
+    )]
+    pub resize: Option<String>,
+
+    #[arg(
+        long = "resize-pct",
+        value_name = "percent",
+        help = "Scale the cropped image by a percentage instead of --resize."
+    )]
+    pub resize_pct: Option<f64>,
+
+    #[arg(
+        long = "filter",
+        value_name = "filter",
+        help = "Resize filter to use. Defaults to Lanczos3 when downscaling, Nearest otherwise."
+    )]
+    pub filter: Option<ResizeFilter>,
+
+    #[arg(
+        long = "pad",
+        value_name = "px",
+        default_value_t = 0,
+        help = "Pad the (optionally shadowed) image with --bg, in pixels."
+    )]
+    pub pad: u32,
+
+    #[arg(
+        long = "bg",
+        value_name = "RRGGBB[AA]",
+        help = "Padding/canvas color. Defaults to transparent."
+    )]
+    pub bg: Option<String>,
+
+    #[arg(long = "shadow", help = "Render a soft drop shadow behind the image.")]
+    pub shadow: bool,
+
+    #[arg(
+        long = "radius",
+        value_name = "px",
+        default_value_t = 0,
+        help = "Round the image's corners by this radius, in pixels."
+    )]
+    pub radius: u32,
+
+    #[arg(
+        short = 'O',
+        long = "optimize",
+        value_name = "level",
+        value_parser = clap::value_parser!(u8).range(0..=6),
+        default_value_t = 0,
+        help = "Lossless PNG optimization level (0 = off, 6 = most exhaustive)."
+    )]
+    pub optimize: u8,
+
     #[arg(value_name = "output-file")]
     pub output_file: Option<PathBuf>,
 }
 
+#[derive(Args, Debug)]
+pub struct RecordArgs {
+    #[arg(
+        short = 'g',
+        value_name = "geometry",
+        help = "Set the region to record."
+    )]
+    pub geometry: Option<String>,
+
+    #[arg(
+        short = 'G',
+        long = "select",
+        help = "Interactively select the region to record using slurp."
+    )]
+    pub select: bool,
+
+    #[arg(
+        long = "slurp",
+        value_name = "args",
+        help = "Extra arguments to forward to slurp, e.g. \"-d -c ff0000\"."
+    )]
+    pub slurp_args: Option<String>,
+
+    #[arg(
+        short = 'o',
+        value_name = "output",
+        help = "Set the output name to record."
+    )]
+    pub output: Option<String>,
+
+    #[arg(
+        long = "fps",
+        value_name = "fps",
+        default_value_t = 30,
+        help = "Target capture frame rate."
+    )]
+    pub fps: u32,
+
+    #[arg(
+        long = "duration",
+        value_name = "seconds",
+        help = "Stop recording automatically after this many seconds."
+    )]
+    pub duration: Option<f64>,
+
+    #[arg(short = 'c', help = "Include cursors in the recording.")]
+    pub cursor: bool,
+
+    #[arg(
+        value_name = "output-file",
+        help = "Output video file; format is chosen by extension (.mp4/.webm)."
+    )]
+    pub output_file: PathBuf,
+}
+
 #[derive(Args, Debug)]
 pub struct CopyFileArgs {
     #[arg(
@@ -140,3 +279,37 @@ pub struct CopyFileArgs {
     #[arg(value_name = "path", help = "File to copy.")]
     pub path: PathBuf,
 }
+
+#[derive(Args, Debug)]
+pub struct ConvertArgs {
+    #[arg(value_name = "input", help = "Image to read; any format the image crate can decode.")]
+    pub input: Option<PathBuf>,
+
+    #[arg(value_name = "output", help = "Output path; format is chosen by extension.")]
+    pub output: Option<PathBuf>,
+
+    #[arg(
+        short = 'q',
+        value_name = "quality",
+        value_parser = clap::value_parser!(u8).range(0..=100),
+        default_value_t = 80,
+        help = "Set the JPEG filetype compression rate (0-100)."
+    )]
+    pub quality: u8,
+
+    #[arg(
+        short = 'O',
+        long = "optimize",
+        value_name = "level",
+        value_parser = clap::value_parser!(u8).range(0..=6),
+        default_value_t = 0,
+        help = "Lossless PNG optimization level (0 = off, 6 = most exhaustive)."
+    )]
+    pub optimize: u8,
+
+    #[arg(
+        long = "list-formats",
+        help = "List supported formats and exit."
+    )]
+    pub list_formats: bool,
+}
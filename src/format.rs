@@ -0,0 +1,90 @@
+//! Central registry of image formats hqs can read/write.
+//!
+//! `OutputFormat` is both the `-t` flag's clap `ValueEnum` (for `capture`) and
+//! the type the capture/finalize/convert save paths dispatch on, so adding a
+//! new format only means adding a variant here.
+
+use clap::ValueEnum;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Png,
+    Ppm,
+    #[value(alias = "jpg")]
+    Jpeg,
+    Qoi,
+    #[value(name = "webp")]
+    WebP,
+}
+
+impl OutputFormat {
+    pub const ALL: [OutputFormat; 5] = [
+        OutputFormat::Png,
+        OutputFormat::Ppm,
+        OutputFormat::Jpeg,
+        OutputFormat::Qoi,
+        OutputFormat::WebP,
+    ];
+
+    /// All extensions recognized for this format; `extensions()[0]` is canonical.
+    pub fn extensions(self) -> &'static [&'static str] {
+        match self {
+            OutputFormat::Png => &["png"],
+            OutputFormat::Ppm => &["ppm", "pnm"],
+            OutputFormat::Jpeg => &["jpeg", "jpg"],
+            OutputFormat::Qoi => &["qoi"],
+            OutputFormat::WebP => &["webp"],
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            OutputFormat::Png => "image/png",
+            OutputFormat::Ppm => "image/x-portable-pixmap",
+            OutputFormat::Jpeg => "image/jpeg",
+            OutputFormat::Qoi => "image/qoi",
+            OutputFormat::WebP => "image/webp",
+        }
+    }
+
+    /// Whether `-q`/`--quality` has any effect on this format. Note that
+    /// `WebP` is NOT lossy here despite the name suggesting otherwise: the
+    /// `image` crate's WebP encoder only emits lossless VP8L, with no quality
+    /// knob, so a `.webp` screenshot is typically the same size as or larger
+    /// than PNG rather than JPEG-like savings.
+    pub fn is_lossy(self) -> bool {
+        matches!(self, OutputFormat::Jpeg)
+    }
+
+    pub fn image_format(self) -> image::ImageFormat {
+        match self {
+            OutputFormat::Png => image::ImageFormat::Png,
+            OutputFormat::Ppm => image::ImageFormat::Pnm,
+            OutputFormat::Jpeg => image::ImageFormat::Jpeg,
+            OutputFormat::Qoi => image::ImageFormat::Qoi,
+            OutputFormat::WebP => image::ImageFormat::WebP,
+        }
+    }
+
+    pub fn from_extension(ext: &str) -> Option<OutputFormat> {
+        let ext = ext.to_ascii_lowercase();
+        OutputFormat::ALL
+            .into_iter()
+            .find(|format| format.extensions().contains(&ext.as_str()))
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.extensions()[0])
+    }
+}
+
+/// All extensions hqs can write to, for `hqs convert --list-formats`.
+pub fn supported_extensions() -> Vec<&'static str> {
+    OutputFormat::ALL
+        .iter()
+        .flat_map(|format| format.extensions().iter().copied())
+        .collect()
+}
@@ -1,5 +1,7 @@
 mod app;
 mod cli;
+mod format;
+mod optimize;
 
 use clap::Parser;
 use cli::Cli;
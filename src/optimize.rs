@@ -0,0 +1,363 @@
+//! A small, self-contained lossless PNG optimizer, in the spirit of oxipng: it
+//! takes an already-encoded PNG, tries a handful of cheaper re-encodings of the
+//! same pixels, and keeps whichever ends up smallest.
+//!
+//! Only non-interlaced 8-bit grayscale, RGB and RGBA images are understood;
+//! anything else (grayscale with alpha, interlaced, 16-bit, already indexed)
+//! is passed through unchanged.
+
+use flate2::Compression;
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use std::io::{Read, Write};
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+const FILTERS: [u8; 5] = [0, 1, 2, 3, 4]; // None, Sub, Up, Average, Paeth
+
+/// Optimize `png`, an already-encoded PNG file, at the given level (0 = disabled,
+/// 6 = most exhaustive). Returns the original bytes unchanged if optimization
+/// isn't applicable or doesn't help.
+pub fn optimize_png(png: &[u8], level: u8) -> Vec<u8> {
+    if level == 0 {
+        return png.to_vec();
+    }
+
+    let Some(image) = decode(png) else {
+        return png.to_vec();
+    };
+
+    let mut best = png.to_vec();
+
+    if let Some(candidate) = encode_truecolor(&image, level) {
+        if candidate.len() < best.len() {
+            best = candidate;
+        }
+    }
+
+    if level >= 5 {
+        if let Some(candidate) = encode_indexed(&image, level) {
+            if candidate.len() < best.len() {
+                best = candidate;
+            }
+        }
+    }
+
+    best
+}
+
+struct RawImage {
+    width: u32,
+    height: u32,
+    channels: u8, // 1 = grayscale, 3 = rgb, 4 = rgba
+    pixels: Vec<u8>,
+}
+
+fn decode(png: &[u8]) -> Option<RawImage> {
+    if png.len() < 8 || png[..8] != SIGNATURE {
+        return None;
+    }
+
+    let mut width = 0u32;
+    let mut height = 0u32;
+    let mut bit_depth = 0u8;
+    let mut color_type = 0u8;
+    let mut idat = Vec::new();
+
+    let mut pos = 8;
+    while pos + 8 <= png.len() {
+        let len = u32::from_be_bytes(png[pos..pos + 4].try_into().ok()?) as usize;
+        let kind = &png[pos + 4..pos + 8];
+        let data_start = pos + 8;
+        let data_end = data_start.checked_add(len)?;
+        if data_end + 4 > png.len() {
+            return None;
+        }
+        let data = &png[data_start..data_end];
+
+        match kind {
+            b"IHDR" => {
+                if data.len() != 13 {
+                    return None;
+                }
+                width = u32::from_be_bytes(data[0..4].try_into().ok()?);
+                height = u32::from_be_bytes(data[4..8].try_into().ok()?);
+                bit_depth = data[8];
+                color_type = data[9];
+                let interlace = data[12];
+                if interlace != 0 {
+                    return None;
+                }
+            }
+            b"IDAT" => idat.extend_from_slice(data),
+            b"IEND" => break,
+            _ => {}
+        }
+
+        pos = data_end + 4;
+    }
+
+    if bit_depth != 8 {
+        return None;
+    }
+    let channels = match color_type {
+        0 => 1,
+        2 => 3,
+        6 => 4,
+        _ => return None,
+    };
+
+    let mut inflated = Vec::new();
+    ZlibDecoder::new(idat.as_slice())
+        .read_to_end(&mut inflated)
+        .ok()?;
+
+    let pixels = unfilter(&inflated, width, height, channels)?;
+    Some(RawImage {
+        width,
+        height,
+        channels,
+        pixels,
+    })
+}
+
+fn unfilter(filtered: &[u8], width: u32, height: u32, channels: u8) -> Option<Vec<u8>> {
+    let bpp = channels as usize;
+    let stride = width as usize * bpp;
+    let mut out = vec![0u8; stride * height as usize];
+    let mut pos = 0;
+
+    for row in 0..height as usize {
+        let filter = *filtered.get(pos)?;
+        pos += 1;
+        let src = filtered.get(pos..pos + stride)?;
+        pos += stride;
+
+        let (prev, cur) = out.split_at_mut(row * stride);
+        let prev_row = if row == 0 {
+            None
+        } else {
+            Some(&prev[(row - 1) * stride..row * stride])
+        };
+        let cur_row = &mut cur[..stride];
+
+        for i in 0..stride {
+            let a = if i >= bpp { cur_row[i - bpp] } else { 0 };
+            let b = prev_row.map(|r| r[i]).unwrap_or(0);
+            let c = if i >= bpp {
+                prev_row.map(|r| r[i - bpp]).unwrap_or(0)
+            } else {
+                0
+            };
+            let x = src[i];
+            cur_row[i] = match filter {
+                0 => x,
+                1 => x.wrapping_add(a),
+                2 => x.wrapping_add(b),
+                3 => x.wrapping_add(((a as u16 + b as u16) / 2) as u8),
+                4 => x.wrapping_add(paeth(a, b, c)),
+                _ => return None,
+            };
+        }
+    }
+
+    Some(out)
+}
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = a as i32 + b as i32 - c as i32;
+    let pa = (p - a as i32).abs();
+    let pb = (p - b as i32).abs();
+    let pc = (p - c as i32).abs();
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+/// Re-filter `raw` (one scanline of `stride` bytes per row) by trying, per
+/// scanline, every filter in `candidates` and keeping whichever minimizes the
+/// sum of absolute signed byte deltas (the same heuristic libpng/oxipng use).
+fn filter_scanlines(raw: &[u8], width_bytes: usize, height: u32, bpp: usize, candidates: &[u8]) -> Vec<u8> {
+    let stride = width_bytes;
+    let mut out = Vec::with_capacity((stride + 1) * height as usize);
+    let mut scratch = vec![0u8; stride];
+
+    for row in 0..height as usize {
+        let cur = &raw[row * stride..(row + 1) * stride];
+        let prev = if row == 0 {
+            None
+        } else {
+            Some(&raw[(row - 1) * stride..row * stride])
+        };
+
+        let mut best_filter = candidates[0];
+        let mut best_cost = u64::MAX;
+        let mut best_bytes = scratch.clone();
+
+        for &filter in candidates {
+            for i in 0..stride {
+                let a = if i >= bpp { cur[i - bpp] } else { 0 };
+                let b = prev.map(|r| r[i]).unwrap_or(0);
+                let c = if i >= bpp {
+                    prev.map(|r| r[i - bpp]).unwrap_or(0)
+                } else {
+                    0
+                };
+                let x = cur[i];
+                scratch[i] = match filter {
+                    0 => x,
+                    1 => x.wrapping_sub(a),
+                    2 => x.wrapping_sub(b),
+                    3 => x.wrapping_sub(((a as u16 + b as u16) / 2) as u8),
+                    4 => x.wrapping_sub(paeth(a, b, c)),
+                    _ => x,
+                };
+            }
+
+            let cost: u64 = scratch.iter().map(|&b| (b as i8).unsigned_abs() as u64).sum();
+            if cost < best_cost {
+                best_cost = cost;
+                best_filter = filter;
+                best_bytes.copy_from_slice(&scratch);
+            }
+        }
+
+        out.push(best_filter);
+        out.extend_from_slice(&best_bytes);
+    }
+
+    out
+}
+
+fn deflate_best(data: &[u8]) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+    encoder.write_all(data).expect("writing to an in-memory encoder cannot fail");
+    encoder.finish().expect("finishing an in-memory encoder cannot fail")
+}
+
+fn filter_candidates(level: u8) -> &'static [u8] {
+    if level <= 2 {
+        &FILTERS[..1] // None only: cheap, still re-deflates at max compression
+    } else if level <= 4 {
+        &[0, 4] // None + Paeth, the two most commonly-winning filters
+    } else {
+        &FILTERS // full search
+    }
+}
+
+fn encode_truecolor(image: &RawImage, level: u8) -> Option<Vec<u8>> {
+    let bpp = image.channels as usize;
+    let stride = image.width as usize * bpp;
+    let filtered = filter_scanlines(&image.pixels, stride, image.height, bpp, filter_candidates(level));
+    let idat = deflate_best(&filtered);
+    let color_type = match image.channels {
+        1 => 0,
+        3 => 2,
+        4 => 6,
+        _ => return None,
+    };
+    Some(assemble(image.width, image.height, 8, color_type, None, None, &idat))
+}
+
+fn encode_indexed(image: &RawImage, level: u8) -> Option<Vec<u8>> {
+    if image.channels != 3 && image.channels != 4 {
+        return None;
+    }
+    let bpp = image.channels as usize;
+    let pixel_count = (image.width as usize) * (image.height as usize);
+
+    let mut palette: Vec<[u8; 4]> = Vec::new();
+    let mut lookup = std::collections::HashMap::new();
+    let mut indices = vec![0u8; pixel_count];
+
+    for (i, px) in image.pixels.chunks(bpp).enumerate() {
+        let rgba = if bpp == 4 {
+            [px[0], px[1], px[2], px[3]]
+        } else {
+            [px[0], px[1], px[2], 255]
+        };
+        let idx = *lookup.entry(rgba).or_insert_with(|| {
+            palette.push(rgba);
+            palette.len() - 1
+        });
+        if idx > 255 {
+            return None;
+        }
+        indices[i] = idx as u8;
+    }
+
+    if palette.len() > 256 {
+        return None;
+    }
+
+    let filtered = filter_scanlines(&indices, image.width as usize, image.height, 1, filter_candidates(level));
+    let idat = deflate_best(&filtered);
+
+    let plte: Vec<u8> = palette.iter().flat_map(|c| [c[0], c[1], c[2]]).collect();
+    let needs_trns = palette.iter().any(|c| c[3] != 255);
+    let trns: Option<Vec<u8>> = needs_trns.then(|| palette.iter().map(|c| c[3]).collect());
+
+    Some(assemble(
+        image.width,
+        image.height,
+        8,
+        3,
+        Some(plte),
+        trns,
+        &idat,
+    ))
+}
+
+fn assemble(
+    width: u32,
+    height: u32,
+    bit_depth: u8,
+    color_type: u8,
+    plte: Option<Vec<u8>>,
+    trns: Option<Vec<u8>>,
+    idat: &[u8],
+) -> Vec<u8> {
+    let mut out = Vec::with_capacity(SIGNATURE.len() + idat.len() + 128);
+    out.extend_from_slice(&SIGNATURE);
+
+    let mut ihdr = Vec::with_capacity(13);
+    ihdr.extend_from_slice(&width.to_be_bytes());
+    ihdr.extend_from_slice(&height.to_be_bytes());
+    ihdr.extend_from_slice(&[bit_depth, color_type, 0, 0, 0]);
+    write_chunk(&mut out, b"IHDR", &ihdr);
+
+    if let Some(plte) = plte {
+        write_chunk(&mut out, b"PLTE", &plte);
+    }
+    if let Some(trns) = trns {
+        write_chunk(&mut out, b"tRNS", &trns);
+    }
+    write_chunk(&mut out, b"IDAT", idat);
+    write_chunk(&mut out, b"IEND", &[]);
+
+    out
+}
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let crc = crc32(&out[start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}